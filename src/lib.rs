@@ -1,7 +1,8 @@
 #[global_allocator]
 pub static GLOBAL_ALLOCATOR: &alloc_cat::AllocCat = &alloc_cat::ALLOCATOR;
 
-use std::io::{BufReader, Cursor, Read, Write};
+use js_sys::Uint8Array;
+use std::io::{Read, Write};
 use wasm_bindgen::prelude::*;
 use zstd::{
     dict::{DecoderDictionary, EncoderDictionary},
@@ -113,6 +114,283 @@ pub fn decompress_with_dict(data: &[u8], dict: &[u8]) -> Result<Vec<u8>, JsValue
     Ok(results)
 }
 
+/// Trains a Zstandard dictionary from a set of representative sample buffers
+///
+/// # Arguments
+///
+/// * `samples` - Sample buffers representative of the data that will be compressed
+/// * `dict_size` - Target size in bytes for the trained dictionary
+#[wasm_bindgen]
+pub fn train_dictionary(samples: Vec<Uint8Array>, dict_size: usize) -> Result<Vec<u8>, JsValue> {
+    if samples.is_empty() {
+        return Err(JsValue::from_str(
+            "At least one sample is required to train a dictionary",
+        ));
+    }
+
+    let owned_samples: Vec<Vec<u8>> = samples.iter().map(|sample| sample.to_vec()).collect();
+    let (buffer, sample_sizes) = concat_samples(&owned_samples);
+
+    zstd::dict::from_continuous(&buffer, &sample_sizes, dict_size)
+        .map_err(|e| JsValue::from_str(&format!("Dictionary training failed: {}", e)))
+}
+
+/// Concatenates sample buffers into one contiguous buffer plus each sample's length,
+/// the layout `ZDICT_trainFromBuffer`-style training expects
+fn concat_samples(samples: &[Vec<u8>]) -> (Vec<u8>, Vec<usize>) {
+    let mut buffer = Vec::new();
+    let mut sample_sizes = Vec::with_capacity(samples.len());
+
+    for sample in samples {
+        sample_sizes.push(sample.len());
+        buffer.extend_from_slice(sample);
+    }
+
+    (buffer, sample_sizes)
+}
+
+/// Metadata parsed from a zstd frame header without decompressing the frame
+#[wasm_bindgen]
+pub struct FrameInfo {
+    content_size: u64,
+    content_size_known: bool,
+    dictionary_id: u32,
+    window_size: u64,
+}
+
+#[wasm_bindgen]
+impl FrameInfo {
+    /// The declared decompressed size, or 0 if the frame header does not declare one
+    #[wasm_bindgen(getter, js_name = contentSize)]
+    pub fn content_size(&self) -> u64 {
+        self.content_size
+    }
+
+    /// Whether the frame header declares a content size at all
+    #[wasm_bindgen(getter, js_name = contentSizeKnown)]
+    pub fn content_size_known(&self) -> bool {
+        self.content_size_known
+    }
+
+    /// The dictionary id required to decompress this frame, or 0 if none
+    #[wasm_bindgen(getter, js_name = dictionaryId)]
+    pub fn dictionary_id(&self) -> u32 {
+        self.dictionary_id
+    }
+
+    /// The window size (in bytes) implied by the frame header
+    #[wasm_bindgen(getter, js_name = windowSize)]
+    pub fn window_size(&self) -> u64 {
+        self.window_size
+    }
+}
+
+/// Inspects a zstd frame header without decompressing it
+///
+/// # Arguments
+///
+/// * `compressed_data` - Zstandard compressed data (only the frame header is read)
+#[wasm_bindgen]
+pub fn frame_info(compressed_data: &[u8]) -> Result<FrameInfo, JsValue> {
+    let header = zstd::zstd_safe::get_frame_header(compressed_data)
+        .map_err(|_| JsValue::from_str("Failed to read frame header: corrupt or truncated frame"))?;
+
+    if header.frame_content_size == zstd::zstd_safe::CONTENTSIZE_ERROR {
+        return Err(JsValue::from_str(
+            "Failed to read frame content size: frame header reports an error",
+        ));
+    }
+
+    let content_size_known = header.frame_content_size != zstd::zstd_safe::CONTENTSIZE_UNKNOWN;
+
+    Ok(FrameInfo {
+        content_size: if content_size_known {
+            header.frame_content_size
+        } else {
+            0
+        },
+        content_size_known,
+        dictionary_id: header.dictionary_id,
+        window_size: header.window_size,
+    })
+}
+
+const PACK_MAGIC: u8 = 0xB5;
+const PACK_VERSION: u8 = 1;
+
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        buf.push(byte);
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+fn read_varint(buf: &[u8]) -> Option<(u64, usize)> {
+    let mut value = 0u64;
+    let mut shift = 0u32;
+
+    for (i, &byte) in buf.iter().enumerate() {
+        // A u64 needs at most 10 bytes (7 bits each); a byte beyond that
+        // (shift >= 64) would overflow the shift, so reject it as malformed
+        // rather than let the shift panic in debug builds.
+        if shift >= 64 {
+            return None;
+        }
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Some((value, i + 1));
+        }
+        shift += 7;
+    }
+
+    None
+}
+
+/// Compresses data into a small self-describing container (magic byte, version,
+/// original size and optional dictionary id, followed by the zstd frame)
+///
+/// # Arguments
+///
+/// * `data` - Input data to compress
+/// * `level` - Compression level (1-22, default 6). Higher = better compression but slower
+/// * `dict_id` - Dictionary id to record in the header. The id is stored as metadata only:
+///   `pack` always compresses `data` without a dictionary
+#[wasm_bindgen]
+pub fn pack(data: &[u8], level: Option<i32>, dict_id: Option<u32>) -> Result<Vec<u8>, JsValue> {
+    let compressed = compress(data, level)?;
+
+    let mut blob = Vec::with_capacity(compressed.len() + 16);
+    blob.push(PACK_MAGIC);
+    blob.push(PACK_VERSION);
+    write_varint(&mut blob, data.len() as u64);
+
+    match dict_id {
+        Some(id) => {
+            blob.push(1);
+            blob.extend_from_slice(&id.to_le_bytes());
+        }
+        None => blob.push(0),
+    }
+
+    blob.extend_from_slice(&compressed);
+    Ok(blob)
+}
+
+struct PackHeader {
+    original_size: u64,
+    dict_id: Option<u32>,
+    payload_offset: usize,
+}
+
+fn parse_pack_header(blob: &[u8]) -> Result<PackHeader, JsValue> {
+    let mut cursor = 0usize;
+
+    let magic = *blob
+        .get(cursor)
+        .ok_or_else(|| JsValue::from_str("Blob too short: missing magic byte"))?;
+    cursor += 1;
+    if magic != PACK_MAGIC {
+        return Err(JsValue::from_str(
+            "Invalid magic byte: not a recognized zstd-wasm-vn container",
+        ));
+    }
+
+    let version = *blob
+        .get(cursor)
+        .ok_or_else(|| JsValue::from_str("Blob too short: missing version byte"))?;
+    cursor += 1;
+    if version != PACK_VERSION {
+        return Err(JsValue::from_str(&format!(
+            "Unsupported container version: {}",
+            version
+        )));
+    }
+
+    let (original_size, consumed) = read_varint(&blob[cursor..])
+        .ok_or_else(|| JsValue::from_str("Blob too short: malformed original size"))?;
+    cursor += consumed;
+
+    let has_dict_id = *blob
+        .get(cursor)
+        .ok_or_else(|| JsValue::from_str("Blob too short: missing dictionary flag"))?;
+    cursor += 1;
+
+    let dict_id = if has_dict_id == 1 {
+        let id_bytes = blob
+            .get(cursor..cursor + 4)
+            .ok_or_else(|| JsValue::from_str("Blob too short: missing dictionary id"))?;
+        cursor += 4;
+        Some(u32::from_le_bytes(id_bytes.try_into().unwrap()))
+    } else {
+        None
+    };
+
+    Ok(PackHeader {
+        original_size,
+        dict_id,
+        payload_offset: cursor,
+    })
+}
+
+fn check_unpacked_size(decompressed: &[u8], original_size: u64) -> Result<(), JsValue> {
+    if decompressed.len() as u64 != original_size {
+        return Err(JsValue::from_str(&format!(
+            "Decompressed size {} does not match the size recorded in the header ({}): blob is corrupt",
+            decompressed.len(),
+            original_size
+        )));
+    }
+
+    Ok(())
+}
+
+/// Decompresses a container produced by `pack`
+///
+/// Fails if the blob records a dictionary id, since this entry point has no
+/// dictionary to decompress with; use `unpack_with_dict` for those blobs.
+///
+/// # Arguments
+///
+/// * `blob` - A blob produced by `pack`
+#[wasm_bindgen]
+pub fn unpack(blob: &[u8]) -> Result<Vec<u8>, JsValue> {
+    let header = parse_pack_header(blob)?;
+
+    if header.dict_id.is_some() {
+        return Err(JsValue::from_str(
+            "Blob requires a dictionary to decompress; use unpack_with_dict()",
+        ));
+    }
+
+    let decompressed = decompress(&blob[header.payload_offset..])?;
+    check_unpacked_size(&decompressed, header.original_size)?;
+
+    Ok(decompressed)
+}
+
+/// Decompresses a container produced by `pack` with a `dict_id`, using the supplied dictionary
+///
+/// # Arguments
+///
+/// * `blob` - A blob produced by `pack` with `dict_id: Some(_)`
+/// * `dict` - The dictionary recorded against the blob's dictionary id
+#[wasm_bindgen]
+pub fn unpack_with_dict(blob: &[u8], dict: &[u8]) -> Result<Vec<u8>, JsValue> {
+    let header = parse_pack_header(blob)?;
+
+    let decompressed = decompress_with_dict(&blob[header.payload_offset..], dict)?;
+    check_unpacked_size(&decompressed, header.original_size)?;
+
+    Ok(decompressed)
+}
+
 /// ZSTD compression and decompression for WebAssembly
 #[wasm_bindgen]
 pub struct Zstd {}
@@ -143,6 +421,11 @@ impl Zstd {
         decompress_with_dict(compressed_data, dict)
     }
 
+    #[wasm_bindgen(js_name = trainDictionary)]
+    pub fn train_dictionary(samples: Vec<Uint8Array>, dict_size: usize) -> Result<Vec<u8>, JsValue> {
+        train_dictionary(samples, dict_size)
+    }
+
     /// Returns the recommended default compression level
     #[wasm_bindgen(js_name = defaultCompressionLevel)]
     pub fn default_compression_level() -> i32 {
@@ -184,6 +467,233 @@ impl Zstd {
         }
         (1.0 - (compressed_size as f64 / original_size as f64)) * 100.0
     }
+
+    /// Inspects a zstd frame header without decompressing it
+    ///
+    /// Lets callers size an output buffer ahead of time, detect whether a frame
+    /// requires a dictionary, and reject oversized frames before allocating for them.
+    ///
+    /// # Arguments
+    ///
+    /// * `compressed_data` - Zstandard compressed data (only the frame header is read)
+    #[wasm_bindgen(js_name = frameInfo)]
+    pub fn frame_info(compressed_data: &[u8]) -> Result<FrameInfo, JsValue> {
+        frame_info(compressed_data)
+    }
+
+    #[wasm_bindgen]
+    pub fn pack(data: &[u8], level: Option<i32>, dict_id: Option<u32>) -> Result<Vec<u8>, JsValue> {
+        pack(data, level, dict_id)
+    }
+
+    #[wasm_bindgen]
+    pub fn unpack(blob: &[u8]) -> Result<Vec<u8>, JsValue> {
+        unpack(blob)
+    }
+
+    #[wasm_bindgen(js_name = unpackWithDict)]
+    pub fn unpack_with_dict(blob: &[u8], dict: &[u8]) -> Result<Vec<u8>, JsValue> {
+        unpack_with_dict(blob, dict)
+    }
+}
+
+/// A dictionary prepared once (a `DecoderDictionary` plus one cached `EncoderDictionary`
+/// per compression level) and reused across many compress/decompress calls
+#[wasm_bindgen]
+pub struct ZstdDictionary {
+    dict: Vec<u8>,
+    decoder: DecoderDictionary<'static>,
+    encoders: std::collections::HashMap<i32, EncoderDictionary<'static>>,
+}
+
+#[wasm_bindgen]
+impl ZstdDictionary {
+    /// Prepares a dictionary handle from raw dictionary bytes
+    #[wasm_bindgen(constructor)]
+    pub fn new(dict: &[u8]) -> ZstdDictionary {
+        ZstdDictionary {
+            dict: dict.to_vec(),
+            decoder: DecoderDictionary::copy(dict),
+            encoders: std::collections::HashMap::new(),
+        }
+    }
+
+    /// Compresses data against the prepared dictionary
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Input data to compress
+    /// * `level` - Compression level (1-22, default 6). Higher = better compression but slower
+    pub fn compress(&mut self, data: &[u8], level: Option<i32>) -> Result<Vec<u8>, JsValue> {
+        let compression_level = level.unwrap_or(DEFAULT_COMPRESSION_LEVEL);
+
+        if compression_level < MIN_COMPRESSION_LEVEL || compression_level > MAX_COMPRESSION_LEVEL {
+            return Err(JsValue::from_str(&format!(
+                "Compression level must be between {} and {}",
+                MIN_COMPRESSION_LEVEL, MAX_COMPRESSION_LEVEL
+            )));
+        }
+
+        let dict = &self.dict;
+        let encoder_dict = self
+            .encoders
+            .entry(compression_level)
+            .or_insert_with(|| EncoderDictionary::copy(dict, compression_level));
+
+        let mut results = Vec::<u8>::new();
+        let mut encoder = match Encoder::with_prepared_dictionary(&mut results, encoder_dict) {
+            Ok(e) => e,
+            Err(e) => {
+                return Err(JsValue::from_str(&e.to_string()));
+            }
+        };
+
+        if let Err(err) = encoder.write_all(data) {
+            return Err(JsValue::from_str(&err.to_string()));
+        }
+        if let Err(err) = encoder.finish() {
+            return Err(JsValue::from_str(&err.to_string()));
+        }
+
+        Ok(results)
+    }
+
+    /// Decompresses data against the prepared dictionary
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Zstandard compressed data
+    pub fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, JsValue> {
+        let mut decoder = match Decoder::with_prepared_dictionary(data, &self.decoder) {
+            Ok(d) => d,
+            Err(e) => {
+                return Err(JsValue::from_str(&e.to_string()));
+            }
+        };
+
+        let mut results = Vec::<u8>::new();
+
+        if let Err(err) = decoder.read_to_end(&mut results) {
+            return Err(JsValue::from_str(&err.to_string()));
+        }
+
+        decoder.finish();
+
+        Ok(results)
+    }
+}
+
+/// A builder for advanced zstd encoder parameters beyond the basic compression level
+/// (window log, long-distance matching, checksum, embedded content size)
+#[wasm_bindgen]
+pub struct ZstdParams {
+    level: i32,
+    window_log: Option<u32>,
+    enable_long_distance_matching: bool,
+    checksum: bool,
+    content_size_flag: bool,
+}
+
+#[wasm_bindgen]
+impl ZstdParams {
+    /// Creates a new params builder at the given compression level
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - Compression level (1-22, default 6). Higher = better compression but slower
+    #[wasm_bindgen(constructor)]
+    pub fn new(level: Option<i32>) -> Result<ZstdParams, JsValue> {
+        let compression_level = level.unwrap_or(DEFAULT_COMPRESSION_LEVEL);
+
+        if compression_level < MIN_COMPRESSION_LEVEL || compression_level > MAX_COMPRESSION_LEVEL {
+            return Err(JsValue::from_str(&format!(
+                "Compression level must be between {} and {}",
+                MIN_COMPRESSION_LEVEL, MAX_COMPRESSION_LEVEL
+            )));
+        }
+
+        Ok(ZstdParams {
+            level: compression_level,
+            window_log: None,
+            enable_long_distance_matching: false,
+            checksum: false,
+            content_size_flag: false,
+        })
+    }
+
+    /// Sets the window log (in bits) used for long-distance matching
+    #[wasm_bindgen(js_name = windowLog)]
+    pub fn window_log(mut self, window_log: u32) -> ZstdParams {
+        self.window_log = Some(window_log);
+        self
+    }
+
+    /// Enables long-distance matching, which improves ratio on large, repetitive input
+    #[wasm_bindgen(js_name = enableLongDistanceMatching)]
+    pub fn enable_long_distance_matching(mut self, enable: bool) -> ZstdParams {
+        self.enable_long_distance_matching = enable;
+        self
+    }
+
+    /// Toggles the content checksum stored in the frame
+    pub fn checksum(mut self, enable: bool) -> ZstdParams {
+        self.checksum = enable;
+        self
+    }
+
+    /// Toggles embedding the decompressed size in the frame header
+    #[wasm_bindgen(js_name = contentSizeFlag)]
+    pub fn content_size_flag(mut self, enable: bool) -> ZstdParams {
+        self.content_size_flag = enable;
+        self
+    }
+
+    /// Compresses data using the configured parameters
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Input data to compress
+    pub fn compress(&self, data: &[u8]) -> Result<Vec<u8>, JsValue> {
+        let mut encoder = Encoder::new(Vec::new(), self.level)
+            .map_err(|e| JsValue::from_str(&format!("Encoder creation failed: {}", e)))?;
+
+        if let Some(window_log) = self.window_log {
+            encoder
+                .set_parameter(zstd::stream::raw::CParameter::WindowLog(window_log))
+                .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        }
+
+        encoder
+            .set_parameter(zstd::stream::raw::CParameter::EnableLongDistanceMatching(
+                self.enable_long_distance_matching,
+            ))
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        encoder
+            .set_parameter(zstd::stream::raw::CParameter::ChecksumFlag(self.checksum))
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        encoder
+            .set_parameter(zstd::stream::raw::CParameter::ContentSizeFlag(
+                self.content_size_flag,
+            ))
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        if self.content_size_flag {
+            // The streaming encoder only writes a content size it has been told up front
+            encoder
+                .set_pledged_src_size(Some(data.len() as u64))
+                .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        }
+
+        encoder
+            .write_all(data)
+            .map_err(|e| JsValue::from_str(&format!("Compression failed: {}", e)))?;
+
+        encoder
+            .finish()
+            .map_err(|e| JsValue::from_str(&format!("Finalization failed: {}", e)))
+    }
 }
 
 /// ==================================== [Streaming] ====================================
@@ -240,69 +750,105 @@ impl ZstdCompressor {
     }
 }
 
-/// Streaming decompression for large data
+/// Streaming decompression for large data, fed incrementally via `push`/`pull`
+/// instead of requiring the whole compressed payload up front
 #[wasm_bindgen]
 pub struct ZstdDecompressor {
-    // The Decoder itself implements `Read` to yield UNCOMPRESSED data.
-    // The inner `Cursor` holds the COMPRESSED input bytes.
-    // We use a Box<dyn Read> for the inner reader to handle the complexity
-    // of the lifetime and the fact that Decoder::new wraps the Cursor in a BufReader.
-    // However, the original structure with BufReader<Cursor<Vec<u8>>> is fine for Wasm
-    // where we often just pass the whole compressed byte array.
-    decoder: Option<Decoder<'static, BufReader<Cursor<Vec<u8>>>>>,
+    ctx: zstd::zstd_safe::DCtx<'static>,
+    pending_input: Vec<u8>,
+    finished: bool,
+}
+
+impl Default for ZstdDecompressor {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[wasm_bindgen]
 impl ZstdDecompressor {
-    /// Creates a new streaming decompressor
+    /// Creates a new incremental decompressor
     #[wasm_bindgen(constructor)]
-    pub fn new(compressed_data: &[u8]) -> Result<ZstdDecompressor, JsValue> {
-        // Use Cursor to treat the Vec<u8> as a stream (implements io::Read)
-        let cursor = Cursor::new(compressed_data.to_vec());
+    pub fn new() -> ZstdDecompressor {
+        ZstdDecompressor {
+            ctx: zstd::zstd_safe::DCtx::create(),
+            pending_input: Vec::new(),
+            finished: false,
+        }
+    }
 
-        // Decoder::new automatically wraps the reader in a BufReader for efficiency
-        let decoder = Decoder::new(cursor)
-            .map_err(|e| JsValue::from_str(&format!("Decoder creation failed: {}", e)))?;
+    /// Appends a chunk of compressed input as it arrives
+    pub fn push(&mut self, compressed_chunk: &[u8]) {
+        self.pending_input.extend_from_slice(compressed_chunk);
+    }
 
-        Ok(ZstdDecompressor {
-            decoder: Some(decoder),
-        })
+    /// Whether the frame has been fully decompressed
+    ///
+    /// An empty result from `pull` is ambiguous on its own: it means either the
+    /// frame is finished or not enough input has arrived yet to produce output
+    /// (starvation). Check `isFinished`, or call `finish`, to tell them apart
+    /// rather than treating an empty `pull` as end-of-stream.
+    #[wasm_bindgen(getter, js_name = isFinished)]
+    pub fn is_finished(&self) -> bool {
+        self.finished
     }
 
-    /// Decompresses a chunk of data
-    pub fn decompress_chunk(&mut self, max_output_size: usize) -> Result<Vec<u8>, JsValue> {
-        // The Option is only None if `stream_to_end` or an equivalent consuming method was called.
-        let decoder = self
-            .decoder
-            .as_mut()
-            .ok_or_else(|| JsValue::from_str("Decompressor has been finalized/consumed."))?;
+    /// Decompresses as much output as is currently available
+    ///
+    /// # Arguments
+    ///
+    /// * `max_output_size` - Upper bound, in bytes, on the output produced by this call
+    pub fn pull(&mut self, max_output_size: usize) -> Result<Vec<u8>, JsValue> {
+        if self.finished {
+            return Err(JsValue::from_str("Decompressor has already finished"));
+        }
 
-        // 1. Prepare output buffer
-        let mut buffer = vec![0u8; max_output_size];
+        let mut output = vec![0u8; max_output_size];
+        let mut out_buffer = zstd::zstd_safe::OutBuffer::around(&mut output);
+        let mut in_buffer = zstd::zstd_safe::InBuffer::around(&self.pending_input);
 
-        // 2. Read decompressed data from the decoder
-        let bytes_read = decoder
-            .read(&mut buffer)
-            .map_err(|e| JsValue::from_str(&format!("Decompression failed: {}", e)))?;
+        let hint = self
+            .ctx
+            .decompress_stream(&mut out_buffer, &mut in_buffer)
+            .map_err(|code| {
+                JsValue::from_str(&format!(
+                    "Decompression failed: {}",
+                    zstd::zstd_safe::get_error_name(code)
+                ))
+            })?;
 
-        // 3. Truncate buffer to actual read size
-        buffer.truncate(bytes_read);
+        let consumed = in_buffer.pos();
+        let produced = out_buffer.pos();
 
-        Ok(buffer)
+        self.pending_input.drain(..consumed);
+        output.truncate(produced);
+
+        if hint == 0 && self.pending_input.is_empty() {
+            self.finished = true;
+        }
+
+        Ok(output)
     }
 
-    /// Decompresses all remaining data and consumes the decoder.
-    pub fn finalize(&mut self) -> Result<Vec<u8>, JsValue> {
-        if let Some(mut decoder) = self.decoder.take() {
-            let mut result = Vec::new();
-            decoder
-                .read_to_end(&mut result)
-                .map_err(|e| JsValue::from_str(&format!("Final read failed: {}", e)))?;
+    /// Validates that the frame consumed so far terminated cleanly
+    ///
+    /// Errors if compressed input remains that hasn't been consumed by `pull`,
+    /// or if `pull` has not yet decoded the end of the frame, since either means
+    /// the frame is incomplete or truncated.
+    pub fn finish(&mut self) -> Result<(), JsValue> {
+        if !self.pending_input.is_empty() {
+            return Err(JsValue::from_str(
+                "Unconsumed compressed input remains: the frame is incomplete",
+            ));
+        }
 
-            Ok(result)
-        } else {
-            Err(JsValue::from_str("Decompressor has already been finalized"))
+        if !self.finished {
+            return Err(JsValue::from_str(
+                "Compressed input ended before the end of the frame: the frame is truncated",
+            ));
         }
+
+        Ok(())
     }
 }
 
@@ -332,6 +878,52 @@ mod tests {
         assert_eq!(data, decompressed.as_slice());
     }
 
+    #[test]
+    fn test_prepared_dictionary_roundtrip() {
+        let data = b"Hello, World! This is a test string for ZSTD compression.";
+
+        let mut prepared = ZstdDictionary::new(DICT);
+        let compressed = prepared.compress(data, None).unwrap();
+        let decompressed = prepared.decompress(&compressed).unwrap();
+
+        assert_eq!(data, decompressed.as_slice());
+    }
+
+    #[test]
+    fn test_prepared_dictionary_caches_encoder_per_level() {
+        let data = b"Hello, World! This is a test string for ZSTD compression.";
+
+        let mut prepared = ZstdDictionary::new(DICT);
+        for level in 1..=3 {
+            let compressed = prepared.compress(data, Some(level)).unwrap();
+            let decompressed = prepared.decompress(&compressed).unwrap();
+            assert_eq!(data, decompressed.as_slice());
+        }
+        assert_eq!(prepared.encoders.len(), 3);
+    }
+
+    #[test]
+    fn test_concat_samples() {
+        let samples: Vec<Vec<u8>> = vec![b"aaa".to_vec(), b"bb".to_vec(), b"c".to_vec()];
+
+        let (buffer, sample_sizes) = concat_samples(&samples);
+
+        assert_eq!(buffer, b"aaabbc");
+        assert_eq!(sample_sizes, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_train_dictionary() {
+        let samples: Vec<Vec<u8>> = (0..32)
+            .map(|i| format!("{{\"id\":{},\"type\":\"telemetry\"}}", i).into_bytes())
+            .collect();
+        let (buffer, sample_sizes) = concat_samples(&samples);
+
+        let dict = zstd::dict::from_continuous(&buffer, &sample_sizes, 256).unwrap();
+
+        assert!(!dict.is_empty());
+    }
+
     #[test]
     fn test_compress_levels() {
         let data = b"Test data for compression level testing".repeat(100);
@@ -361,6 +953,81 @@ mod tests {
         assert_eq!(savings, 75.0);
     }
 
+    #[test]
+    fn test_pack_unpack_roundtrip() {
+        let data = b"Hello, World! This is a test string for ZSTD compression.";
+
+        let blob = Zstd::pack(data, None, None).unwrap();
+        let unpacked = Zstd::unpack(&blob).unwrap();
+
+        assert_eq!(data, unpacked.as_slice());
+    }
+
+    #[test]
+    fn test_unpack_rejects_wrong_magic() {
+        let mut blob = Zstd::pack(b"payload", None, None).unwrap();
+        blob[0] ^= 0xFF;
+
+        assert!(Zstd::unpack(&blob).is_err());
+    }
+
+    #[test]
+    fn test_unpack_requires_dictionary_when_recorded() {
+        let blob = Zstd::pack(b"payload", None, Some(7)).unwrap();
+
+        assert!(Zstd::unpack(&blob).is_err());
+    }
+
+    #[test]
+    fn test_unpack_with_dict_roundtrip() {
+        let data = b"Hello, World! This is a test string for ZSTD compression.";
+
+        let blob = Zstd::pack(data, None, Some(7)).unwrap();
+        let unpacked = Zstd::unpack_with_dict(&blob, DICT).unwrap();
+
+        assert_eq!(data, unpacked.as_slice());
+    }
+
+    #[test]
+    fn test_unpack_rejects_original_size_mismatch() {
+        let mut blob = Zstd::pack(b"payload", None, None).unwrap();
+        // Header byte 2 is the first byte of the varint-encoded original size;
+        // flip its low bit (keeping the continuation bit clear) so the recorded
+        // size no longer matches the decompressed length.
+        blob[2] ^= 0x01;
+
+        assert!(Zstd::unpack(&blob).is_err());
+    }
+
+    #[test]
+    fn test_unpack_rejects_runaway_varint_instead_of_panicking() {
+        let mut blob = Zstd::pack(b"payload", None, None).unwrap();
+        // Replace the original-size varint with 11 continuation bytes, none of
+        // which ever terminate it: this must be rejected, not overflow the shift.
+        blob.splice(2..3, std::iter::repeat(0x80u8).take(11));
+
+        assert!(Zstd::unpack(&blob).is_err());
+    }
+
+    #[test]
+    fn test_frame_info() {
+        let data = b"Test data for frame header inspection".repeat(10);
+
+        // Plain `compress` streams without a pledged size, so its frames declare no
+        // content size; ask for it explicitly.
+        let compressed = ZstdParams::new(None)
+            .unwrap()
+            .content_size_flag(true)
+            .compress(&data)
+            .unwrap();
+        let info = Zstd::frame_info(&compressed).unwrap();
+
+        assert!(info.content_size_known());
+        assert_eq!(info.content_size(), data.len() as u64);
+        assert_eq!(info.dictionary_id(), 0);
+        assert!(info.window_size() > 0);
+    }
+
     #[test]
     fn test_compress_bound() {
         let input_size = 1000;
@@ -369,6 +1036,23 @@ mod tests {
         assert!(bound >= input_size);
     }
 
+    #[test]
+    fn test_params_compress_with_long_distance_matching() {
+        let data = b"Repetitive data for LDM testing. ".repeat(200);
+
+        let compressed = ZstdParams::new(Some(DEFAULT_COMPRESSION_LEVEL))
+            .unwrap()
+            .window_log(27)
+            .enable_long_distance_matching(true)
+            .checksum(true)
+            .content_size_flag(true)
+            .compress(&data)
+            .unwrap();
+
+        let decompressed = Zstd::decompress(&compressed).unwrap();
+        assert_eq!(data, decompressed);
+    }
+
     #[test]
     fn test_streaming_compression() {
         let data = b"Streaming compression test data".to_vec();
@@ -386,32 +1070,54 @@ mod tests {
 
     #[test]
     fn test_chunked_decompression() {
-        let data = b"This is some test data that is intentionally longer to ensure streaming works across 
+        let data = b"This is some test data that is intentionally longer to ensure streaming works across
         multiple blocks, which is necessary to properly test the chunking logic of the ZstdDecompressor implementation.".to_vec();
 
         let compressed = Zstd::compress(&data, Some(DEFAULT_COMPRESSION_LEVEL)).unwrap();
 
-        let mut decompressor =
-            ZstdDecompressor::new(&compressed).expect("Failed to create decompressor");
-
-        let chunk_size = 10; // Request a small chunk size to force multiple calls
+        let mut decompressor = ZstdDecompressor::default();
+        let pull_size = 10; // Request a small output size to force multiple calls
         let mut decompressed: Vec<u8> = Vec::new();
 
-        loop {
-            let chunk = decompressor
-                .decompress_chunk(chunk_size)
-                .expect("Decompress chunk failed");
+        // Interleave small pushes (as if chunks were arriving over the network) with
+        // pulls, instead of buffering the whole compressed payload before decompressing.
+        for network_chunk in compressed.chunks(8) {
+            decompressor.push(network_chunk);
 
-            // Stop condition: empty chunk indicates EOF
-            if chunk.is_empty() {
-                break;
-            }
+            while !decompressor.is_finished() {
+                let chunk = decompressor
+                    .pull(pull_size)
+                    .expect("Decompress chunk failed");
 
-            // Collect the chunk
-            decompressed.extend_from_slice(&chunk);
+                if chunk.is_empty() {
+                    // Starvation: not enough input has arrived yet for another
+                    // output chunk. Stop pulling and push more input instead of
+                    // treating this as end-of-stream.
+                    break;
+                }
+
+                decompressed.extend_from_slice(&chunk);
+            }
         }
 
-        // Verify
+        decompressor.finish().expect("Frame did not terminate cleanly");
+
         assert_eq!(data, decompressed);
     }
+
+    #[test]
+    fn test_pull_starvation_is_distinct_from_finished() {
+        let data = b"Test data for starvation vs finished".repeat(20);
+        let compressed = Zstd::compress(&data, Some(DEFAULT_COMPRESSION_LEVEL)).unwrap();
+
+        let mut decompressor = ZstdDecompressor::default();
+
+        // Push only the first byte: not enough to produce any output yet.
+        decompressor.push(&compressed[..1]);
+        let chunk = decompressor.pull(64).expect("pull failed");
+
+        assert!(chunk.is_empty());
+        assert!(!decompressor.is_finished());
+        assert!(decompressor.finish().is_err());
+    }
 }